@@ -3,7 +3,8 @@
 //! # What
 //!
 //! This crate provides an iterator that ingests a UTF-8 string and yields
-//! MacRoman code points.
+//! MacRoman code points, and another that goes the other way: ingesting
+//! MacRoman bytes and yielding Unicode characters.
 //!
 //! # Why
 //!
@@ -23,15 +24,27 @@
 //!
 //! - Composed vs decomposed: Both forms of supported characters are supported.
 //! - ¤ vs €: Both characters are converted as $DB; which one is correct
-//!   depends on whether your font predates Mac OS 8.5.
-//! - Ω (capital omega) vs Ω (ohm sign): Both are converted as $BD. The
-//!   question of which is correct only arises when converting *to* Unicode,
-//!   not from it.
+//!   depends on whether your font predates Mac OS 8.5. [`decode`] assumes
+//!   €, the modern meaning; pass [`CurrencyMode::PreMacOS85`] to
+//!   [`decode_with_mode`] for the old one.
+//! - Ω (capital omega) vs Ω (ohm sign): Both are converted as $BD, and
+//!   [`decode`] turns $BD back into the capital omega, since that's the
+//!   character that actually appears on MacRoman fonts.
 //! - The Apple symbol: Apple uses U+F8FF, a character in the Corporate Private
 //!   Use Area, to represent its logo in text. We comply with this usage.
 //! - Unsupported characters: If the crate encounters a Unicode code sequence
 //!   for which it can't find a MacRoman-encodable prefix, it will yield an
-//!   `Err(codepoint)`, step by one code point, and try again.
+//!   `Err(codepoint)`, step by one code point, and try again. [`encode_lossy`]
+//!   instead tries a table of best-fit ASCII/MacRoman substitutions first,
+//!   and only gives up and yields `Err` if that also fails.
+//!
+//! # Performance
+//!
+//! With the (default-on) `memchr` feature, encoding uses the `memchr`
+//! crate to scan past long runs of plain ASCII without a binary search
+//! per byte. `no_std` users who don't want the dependency can disable
+//! default features; encoding still works, just without that
+//! acceleration.
 //!
 //! # Legalese
 //!
@@ -364,50 +377,878 @@ static KNOWN_SEQUENCES: &[(&str, u8)] = &[
     ("\u{FB02}", 223),
 ];
 
+/// The sequences recognized by [`MacEncoding::MacCentralEuropean`],
+/// sorted the same way as [`KNOWN_SEQUENCES`]. Bytes 0–127 are ASCII, as
+/// with every Mac script encoding; bytes 128–255 hold the Central
+/// European accented letters, plus the symbols MacRoman and MacCE have
+/// in common.
+static CENTRAL_EUROPEAN_SEQUENCES: &[(&str, u8)] = &[
+    ("\u{0000}", 0),
+    ("\u{0001}", 1),
+    ("\u{0002}", 2),
+    ("\u{0003}", 3),
+    ("\u{0004}", 4),
+    ("\u{0005}", 5),
+    ("\u{0006}", 6),
+    ("\u{0007}", 7),
+    ("\u{0008}", 8),
+    ("\u{0009}", 9),
+    ("\u{000A}", 10),
+    ("\u{000B}", 11),
+    ("\u{000C}", 12),
+    ("\u{000D}", 13),
+    ("\u{000E}", 14),
+    ("\u{000F}", 15),
+    ("\u{0010}", 16),
+    ("\u{0011}", 17),
+    ("\u{0012}", 18),
+    ("\u{0013}", 19),
+    ("\u{0014}", 20),
+    ("\u{0015}", 21),
+    ("\u{0016}", 22),
+    ("\u{0017}", 23),
+    ("\u{0018}", 24),
+    ("\u{0019}", 25),
+    ("\u{001A}", 26),
+    ("\u{001B}", 27),
+    ("\u{001C}", 28),
+    ("\u{001D}", 29),
+    ("\u{001E}", 30),
+    ("\u{001F}", 31),
+    ("\u{0020}", 32),
+    ("\u{0021}", 33),
+    ("\u{0022}", 34),
+    ("\u{0023}", 35),
+    ("\u{0024}", 36),
+    ("\u{0025}", 37),
+    ("\u{0026}", 38),
+    ("\u{0027}", 39),
+    ("\u{0028}", 40),
+    ("\u{0029}", 41),
+    ("\u{002A}", 42),
+    ("\u{002B}", 43),
+    ("\u{002C}", 44),
+    ("\u{002D}", 45),
+    ("\u{002E}", 46),
+    ("\u{002F}", 47),
+    ("\u{0030}", 48),
+    ("\u{0031}", 49),
+    ("\u{0032}", 50),
+    ("\u{0033}", 51),
+    ("\u{0034}", 52),
+    ("\u{0035}", 53),
+    ("\u{0036}", 54),
+    ("\u{0037}", 55),
+    ("\u{0038}", 56),
+    ("\u{0039}", 57),
+    ("\u{003A}", 58),
+    ("\u{003B}", 59),
+    ("\u{003C}", 60),
+    ("\u{003D}", 61),
+    ("\u{003E}", 62),
+    ("\u{003F}", 63),
+    ("\u{0040}", 64),
+    ("\u{0041}", 65),
+    ("\u{0042}", 66),
+    ("\u{0043}", 67),
+    ("\u{0044}", 68),
+    ("\u{0045}", 69),
+    ("\u{0046}", 70),
+    ("\u{0047}", 71),
+    ("\u{0048}", 72),
+    ("\u{0049}", 73),
+    ("\u{004A}", 74),
+    ("\u{004B}", 75),
+    ("\u{004C}", 76),
+    ("\u{004D}", 77),
+    ("\u{004E}", 78),
+    ("\u{004F}", 79),
+    ("\u{0050}", 80),
+    ("\u{0051}", 81),
+    ("\u{0052}", 82),
+    ("\u{0053}", 83),
+    ("\u{0054}", 84),
+    ("\u{0055}", 85),
+    ("\u{0056}", 86),
+    ("\u{0057}", 87),
+    ("\u{0058}", 88),
+    ("\u{0059}", 89),
+    ("\u{005A}", 90),
+    ("\u{005B}", 91),
+    ("\u{005C}", 92),
+    ("\u{005D}", 93),
+    ("\u{005E}", 94),
+    ("\u{005F}", 95),
+    ("\u{0060}", 96),
+    ("\u{0061}", 97),
+    ("\u{0062}", 98),
+    ("\u{0063}", 99),
+    ("\u{0064}", 100),
+    ("\u{0065}", 101),
+    ("\u{0066}", 102),
+    ("\u{0067}", 103),
+    ("\u{0068}", 104),
+    ("\u{0069}", 105),
+    ("\u{006A}", 106),
+    ("\u{006B}", 107),
+    ("\u{006C}", 108),
+    ("\u{006D}", 109),
+    ("\u{006E}", 110),
+    ("\u{006F}", 111),
+    ("\u{0070}", 112),
+    ("\u{0071}", 113),
+    ("\u{0072}", 114),
+    ("\u{0073}", 115),
+    ("\u{0074}", 116),
+    ("\u{0075}", 117),
+    ("\u{0076}", 118),
+    ("\u{0077}", 119),
+    ("\u{0078}", 120),
+    ("\u{0079}", 121),
+    ("\u{007A}", 122),
+    ("\u{007B}", 123),
+    ("\u{007C}", 124),
+    ("\u{007D}", 125),
+    ("\u{007E}", 126),
+    ("\u{007F}", 127),
+    ("\u{00A0}", 252),
+    ("\u{00A1}", 232),
+    ("\u{00A5}", 219),
+    ("\u{00A7}", 194),
+    ("\u{00A8}", 211),
+    ("\u{00A9}", 208),
+    ("\u{00AA}", 226),
+    ("\u{00AB}", 195),
+    ("\u{00AC}", 233),
+    ("\u{00AE}", 207),
+    ("\u{00B0}", 205),
+    ("\u{00B1}", 216),
+    ("\u{00B4}", 210),
+    ("\u{00B5}", 220),
+    ("\u{00B7}", 240),
+    ("\u{00BA}", 227),
+    ("\u{00BB}", 196),
+    ("\u{00BF}", 231),
+    ("\u{00C4}", 128),
+    ("\u{00C6}", 213),
+    ("\u{00C9}", 131),
+    ("\u{00D6}", 133),
+    ("\u{00D8}", 214),
+    ("\u{00DC}", 134),
+    ("\u{00DF}", 206),
+    ("\u{00E1}", 135),
+    ("\u{00E4}", 138),
+    ("\u{00E6}", 229),
+    ("\u{00E9}", 142),
+    ("\u{00ED}", 146),
+    ("\u{00F7}", 204),
+    ("\u{00F8}", 230),
+    ("\u{0100}", 129),
+    ("\u{0101}", 130),
+    ("\u{0104}", 132),
+    ("\u{0105}", 136),
+    ("\u{0106}", 140),
+    ("\u{0107}", 141),
+    ("\u{010C}", 137),
+    ("\u{010D}", 139),
+    ("\u{010E}", 145),
+    ("\u{010F}", 147),
+    ("\u{0112}", 148),
+    ("\u{0113}", 149),
+    ("\u{0116}", 150),
+    ("\u{0117}", 151),
+    ("\u{0118}", 154),
+    ("\u{0119}", 155),
+    ("\u{011A}", 152),
+    ("\u{011B}", 153),
+    ("\u{012E}", 156),
+    ("\u{012F}", 157),
+    ("\u{0130}", 158),
+    ("\u{0131}", 159),
+    ("\u{0139}", 186),
+    ("\u{013A}", 187),
+    ("\u{013D}", 184),
+    ("\u{013E}", 185),
+    ("\u{0143}", 160),
+    ("\u{0144}", 161),
+    ("\u{0145}", 188),
+    ("\u{0146}", 189),
+    ("\u{0147}", 162),
+    ("\u{0148}", 163),
+    ("\u{014C}", 190),
+    ("\u{014D}", 191),
+    ("\u{0150}", 164),
+    ("\u{0151}", 165),
+    ("\u{0154}", 166),
+    ("\u{0155}", 167),
+    ("\u{0156}", 170),
+    ("\u{0157}", 171),
+    ("\u{0158}", 168),
+    ("\u{0159}", 169),
+    ("\u{015A}", 174),
+    ("\u{015B}", 175),
+    ("\u{0160}", 172),
+    ("\u{0161}", 173),
+    ("\u{0164}", 176),
+    ("\u{0165}", 177),
+    ("\u{0166}", 178),
+    ("\u{0167}", 179),
+    ("\u{016A}", 192),
+    ("\u{016B}", 193),
+    ("\u{0179}", 143),
+    ("\u{017A}", 144),
+    ("\u{017B}", 182),
+    ("\u{017C}", 183),
+    ("\u{017D}", 180),
+    ("\u{017E}", 181),
+    ("\u{0192}", 235),
+    ("\u{02C7}", 253),
+    ("\u{02D8}", 254),
+    ("\u{02D9}", 255),
+    ("\u{03A9}", 228),
+    ("\u{03C0}", 224),
+    ("\u{2013}", 242),
+    ("\u{2014}", 243),
+    ("\u{2018}", 202),
+    ("\u{2019}", 203),
+    ("\u{201A}", 250),
+    ("\u{201C}", 200),
+    ("\u{201D}", 201),
+    ("\u{201E}", 199),
+    ("\u{2020}", 238),
+    ("\u{2021}", 239),
+    ("\u{2026}", 241),
+    ("\u{2030}", 246),
+    ("\u{2039}", 197),
+    ("\u{203A}", 198),
+    ("\u{2044}", 249),
+    ("\u{20AC}", 251),
+    ("\u{2122}", 209),
+    ("\u{2202}", 221),
+    ("\u{2206}", 237),
+    ("\u{220F}", 223),
+    ("\u{2211}", 222),
+    ("\u{221A}", 234),
+    ("\u{221E}", 215),
+    ("\u{222B}", 225),
+    ("\u{2248}", 236),
+    ("\u{2260}", 212),
+    ("\u{2264}", 217),
+    ("\u{2265}", 218),
+    ("\u{25CA}", 247),
+    ("\u{F8FF}", 248),
+    ("\u{FB01}", 244),
+    ("\u{FB02}", 245),
+];
+
+/// Best-fit substitutions used by [`encode_lossy`] and [`encode_as_lossy`]
+/// once a code sequence has no entry in the active [`MacEncoding`]'s
+/// table. Sorted by prefix, and searched the same way as
+/// [`KNOWN_SEQUENCES`]. Each entry may expand to more than one output
+/// byte (e.g. the fullwidth ASCII forms collapse to their single-byte
+/// ASCII equivalents, which happen to only ever be one byte, but the
+/// table format doesn't assume that).
+///
+/// This table is intentionally conservative: it only covers code points
+/// that have an obvious, unsurprising ASCII or MacRoman stand-in.
+/// Letters from other scripts (Greek, Cyrillic, etc.) are deliberately
+/// left out, so that they still fall through to `Err`.
+static TRANSLITERATIONS: &[(&str, &[u8])] = &[
+    ("\u{00BC}", b"1/4"),
+    ("\u{00BD}", b"1/2"),
+    ("\u{00BE}", b"3/4"),
+    ("\u{2000}", b" "),
+    ("\u{2001}", b" "),
+    ("\u{2002}", b" "),
+    ("\u{2003}", b" "),
+    ("\u{2004}", b" "),
+    ("\u{2005}", b" "),
+    ("\u{2006}", b" "),
+    ("\u{2007}", b" "),
+    ("\u{2008}", b" "),
+    ("\u{2009}", b" "),
+    ("\u{200A}", b" "),
+    ("\u{2010}", b"-"),
+    ("\u{2011}", b"-"),
+    ("\u{2012}", b"-"),
+    ("\u{2015}", b"-"),
+    ("\u{2017}", b"_"),
+    ("\u{202F}", b" "),
+    ("\u{2032}", b"'"),
+    ("\u{2033}", b"\""),
+    ("\u{205F}", b" "),
+    ("\u{2212}", b"-"),
+    ("\u{2215}", b"/"),
+    ("\u{3000}", b" "),
+    ("\u{FF01}", b"!"),
+    ("\u{FF02}", b"\""),
+    ("\u{FF03}", b"#"),
+    ("\u{FF04}", b"$"),
+    ("\u{FF05}", b"%"),
+    ("\u{FF06}", b"&"),
+    ("\u{FF07}", b"'"),
+    ("\u{FF08}", b"("),
+    ("\u{FF09}", b")"),
+    ("\u{FF0A}", b"*"),
+    ("\u{FF0B}", b"+"),
+    ("\u{FF0C}", b","),
+    ("\u{FF0D}", b"-"),
+    ("\u{FF0E}", b"."),
+    ("\u{FF0F}", b"/"),
+    ("\u{FF10}", b"0"),
+    ("\u{FF11}", b"1"),
+    ("\u{FF12}", b"2"),
+    ("\u{FF13}", b"3"),
+    ("\u{FF14}", b"4"),
+    ("\u{FF15}", b"5"),
+    ("\u{FF16}", b"6"),
+    ("\u{FF17}", b"7"),
+    ("\u{FF18}", b"8"),
+    ("\u{FF19}", b"9"),
+    ("\u{FF1A}", b":"),
+    ("\u{FF1B}", b";"),
+    ("\u{FF1C}", b"<"),
+    ("\u{FF1D}", b"="),
+    ("\u{FF1E}", b">"),
+    ("\u{FF1F}", b"?"),
+    ("\u{FF20}", b"@"),
+    ("\u{FF21}", b"A"),
+    ("\u{FF22}", b"B"),
+    ("\u{FF23}", b"C"),
+    ("\u{FF24}", b"D"),
+    ("\u{FF25}", b"E"),
+    ("\u{FF26}", b"F"),
+    ("\u{FF27}", b"G"),
+    ("\u{FF28}", b"H"),
+    ("\u{FF29}", b"I"),
+    ("\u{FF2A}", b"J"),
+    ("\u{FF2B}", b"K"),
+    ("\u{FF2C}", b"L"),
+    ("\u{FF2D}", b"M"),
+    ("\u{FF2E}", b"N"),
+    ("\u{FF2F}", b"O"),
+    ("\u{FF30}", b"P"),
+    ("\u{FF31}", b"Q"),
+    ("\u{FF32}", b"R"),
+    ("\u{FF33}", b"S"),
+    ("\u{FF34}", b"T"),
+    ("\u{FF35}", b"U"),
+    ("\u{FF36}", b"V"),
+    ("\u{FF37}", b"W"),
+    ("\u{FF38}", b"X"),
+    ("\u{FF39}", b"Y"),
+    ("\u{FF3A}", b"Z"),
+    ("\u{FF3B}", b"["),
+    ("\u{FF3C}", b"\\"),
+    ("\u{FF3D}", b"]"),
+    ("\u{FF3E}", b"^"),
+    ("\u{FF3F}", b"_"),
+    ("\u{FF40}", b"`"),
+    ("\u{FF41}", b"a"),
+    ("\u{FF42}", b"b"),
+    ("\u{FF43}", b"c"),
+    ("\u{FF44}", b"d"),
+    ("\u{FF45}", b"e"),
+    ("\u{FF46}", b"f"),
+    ("\u{FF47}", b"g"),
+    ("\u{FF48}", b"h"),
+    ("\u{FF49}", b"i"),
+    ("\u{FF4A}", b"j"),
+    ("\u{FF4B}", b"k"),
+    ("\u{FF4C}", b"l"),
+    ("\u{FF4D}", b"m"),
+    ("\u{FF4E}", b"n"),
+    ("\u{FF4F}", b"o"),
+    ("\u{FF50}", b"p"),
+    ("\u{FF51}", b"q"),
+    ("\u{FF52}", b"r"),
+    ("\u{FF53}", b"s"),
+    ("\u{FF54}", b"t"),
+    ("\u{FF55}", b"u"),
+    ("\u{FF56}", b"v"),
+    ("\u{FF57}", b"w"),
+    ("\u{FF58}", b"x"),
+    ("\u{FF59}", b"y"),
+    ("\u{FF5A}", b"z"),
+    ("\u{FF5B}", b"{"),
+    ("\u{FF5C}", b"|"),
+    ("\u{FF5D}", b"}"),
+    ("\u{FF5E}", b"~"),
+];
+
+/// Which code point byte $DB ($DB, 219 decimal) decodes to.
+///
+/// Mac OS 8.5 repurposed this code point from the international currency
+/// sign to the euro sign. Fonts and documents that predate that change
+/// use the old meaning, so [`decode_with_mode`] lets a caller pick it
+/// explicitly instead of guessing from context.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum CurrencyMode {
+    /// Decode $DB as U+20AC EURO SIGN (€). This is correct for Mac OS 8.5
+    /// and later, and is what [`decode`] uses.
+    #[default]
+    Euro,
+    /// Decode $DB as U+00A4 CURRENCY SIGN (¤), as on fonts and systems
+    /// that predate Mac OS 8.5.
+    PreMacOS85,
+}
+
+/// Maps each MacRoman byte value to the Unicode code point it decodes to.
+///
+/// Every byte has exactly one precomposed NFC code point, so (apart from
+/// the ambiguous bytes called out in the crate documentation) there's no
+/// real choice to make here. Byte 189 ($BD) decodes to U+03A9 (Ω, Greek
+/// capital omega) rather than U+2126 (Ω, ohm sign); both encode back to
+/// the same byte. Byte 219 ($DB) is handled separately by
+/// [`CurrencyMode`], since which code point is "canonical" depends on
+/// the vintage of the font.
+static DECODE_TABLE: [char; 256] = [
+    '\u{0000}', '\u{0001}', '\u{0002}', '\u{0003}', '\u{0004}', '\u{0005}',
+    '\u{0006}', '\u{0007}', '\u{0008}', '\u{0009}', '\u{000A}', '\u{000B}',
+    '\u{000C}', '\u{000D}', '\u{000E}', '\u{000F}', '\u{0010}', '\u{0011}',
+    '\u{0012}', '\u{0013}', '\u{0014}', '\u{0015}', '\u{0016}', '\u{0017}',
+    '\u{0018}', '\u{0019}', '\u{001A}', '\u{001B}', '\u{001C}', '\u{001D}',
+    '\u{001E}', '\u{001F}', '\u{0020}', '\u{0021}', '\u{0022}', '\u{0023}',
+    '\u{0024}', '\u{0025}', '\u{0026}', '\u{0027}', '\u{0028}', '\u{0029}',
+    '\u{002A}', '\u{002B}', '\u{002C}', '\u{002D}', '\u{002E}', '\u{002F}',
+    '\u{0030}', '\u{0031}', '\u{0032}', '\u{0033}', '\u{0034}', '\u{0035}',
+    '\u{0036}', '\u{0037}', '\u{0038}', '\u{0039}', '\u{003A}', '\u{003B}',
+    '\u{003C}', '\u{003D}', '\u{003E}', '\u{003F}', '\u{0040}', '\u{0041}',
+    '\u{0042}', '\u{0043}', '\u{0044}', '\u{0045}', '\u{0046}', '\u{0047}',
+    '\u{0048}', '\u{0049}', '\u{004A}', '\u{004B}', '\u{004C}', '\u{004D}',
+    '\u{004E}', '\u{004F}', '\u{0050}', '\u{0051}', '\u{0052}', '\u{0053}',
+    '\u{0054}', '\u{0055}', '\u{0056}', '\u{0057}', '\u{0058}', '\u{0059}',
+    '\u{005A}', '\u{005B}', '\u{005C}', '\u{005D}', '\u{005E}', '\u{005F}',
+    '\u{0060}', '\u{0061}', '\u{0062}', '\u{0063}', '\u{0064}', '\u{0065}',
+    '\u{0066}', '\u{0067}', '\u{0068}', '\u{0069}', '\u{006A}', '\u{006B}',
+    '\u{006C}', '\u{006D}', '\u{006E}', '\u{006F}', '\u{0070}', '\u{0071}',
+    '\u{0072}', '\u{0073}', '\u{0074}', '\u{0075}', '\u{0076}', '\u{0077}',
+    '\u{0078}', '\u{0079}', '\u{007A}', '\u{007B}', '\u{007C}', '\u{007D}',
+    '\u{007E}', '\u{007F}',
+    // 0x80..=0xFF: the "high" half of MacRoman.
+    '\u{00C4}', '\u{00C5}', '\u{00C7}', '\u{00C9}', '\u{00D1}', '\u{00D6}',
+    '\u{00DC}', '\u{00E1}', '\u{00E0}', '\u{00E2}', '\u{00E4}', '\u{00E3}',
+    '\u{00E5}', '\u{00E7}', '\u{00E9}', '\u{00E8}', '\u{00EA}', '\u{00EB}',
+    '\u{00ED}', '\u{00EC}', '\u{00EE}', '\u{00EF}', '\u{00F1}', '\u{00F3}',
+    '\u{00F2}', '\u{00F4}', '\u{00F6}', '\u{00F5}', '\u{00FA}', '\u{00F9}',
+    '\u{00FB}', '\u{00FC}', '\u{2020}', '\u{00B0}', '\u{00A2}', '\u{00A3}',
+    '\u{00A7}', '\u{2022}', '\u{00B6}', '\u{00DF}', '\u{00AE}', '\u{00A9}',
+    '\u{2122}', '\u{00B4}', '\u{00A8}', '\u{2260}', '\u{00C6}', '\u{00D8}',
+    '\u{221E}', '\u{00B1}', '\u{2264}', '\u{2265}', '\u{00A5}', '\u{00B5}',
+    '\u{2202}', '\u{2211}', '\u{220F}', '\u{03C0}', '\u{222B}', '\u{00AA}',
+    '\u{00BA}', '\u{03A9}', '\u{00E6}', '\u{00F8}', '\u{00BF}', '\u{00A1}',
+    '\u{00AC}', '\u{221A}', '\u{0192}', '\u{2248}', '\u{2206}', '\u{00AB}',
+    '\u{00BB}', '\u{2026}', '\u{00A0}', '\u{00C0}', '\u{00C3}', '\u{00D5}',
+    '\u{0152}', '\u{0153}', '\u{2013}', '\u{2014}', '\u{201C}', '\u{201D}',
+    '\u{2018}', '\u{2019}', '\u{00F7}', '\u{25CA}', '\u{00FF}', '\u{0178}',
+    '\u{2044}', '\u{20AC}', '\u{2039}', '\u{203A}', '\u{FB01}', '\u{FB02}',
+    '\u{2021}', '\u{00B7}', '\u{201A}', '\u{201E}', '\u{2030}', '\u{00C2}',
+    '\u{00CA}', '\u{00C1}', '\u{00CB}', '\u{00C8}', '\u{00CD}', '\u{00CE}',
+    '\u{00CF}', '\u{00CC}', '\u{00D3}', '\u{00D4}', '\u{F8FF}', '\u{00D2}',
+    '\u{00DA}', '\u{00DB}', '\u{00D9}', '\u{0131}', '\u{02C6}', '\u{02DC}',
+    '\u{00AF}', '\u{02D8}', '\u{02D9}', '\u{02DA}', '\u{00B8}', '\u{02DD}',
+    '\u{02DB}', '\u{02C7}',
+];
+
+/// The classic Macintosh PostScript glyph name for each MacRoman byte
+/// value, as found in the `post` table of most Mac TrueType and
+/// PostScript fonts. Used by [`macroman_glyph_name`].
+///
+/// Control characters have no conventional glyph name and map to
+/// `".notdef"`. Byte 219 ($DB) is named `"currency"`, its name from
+/// before Mac OS 8.5 repurposed the glyph for the euro sign (see
+/// [`CurrencyMode`]); the name stuck even in fonts that redrew the glyph.
+static MACROMAN_GLYPH_NAMES: [&str; 256] = [
+    ".notdef", ".notdef", ".notdef", ".notdef", ".notdef", ".notdef",
+    ".notdef", ".notdef", ".notdef", ".notdef", ".notdef", ".notdef",
+    ".notdef", ".notdef", ".notdef", ".notdef", ".notdef", ".notdef",
+    ".notdef", ".notdef", ".notdef", ".notdef", ".notdef", ".notdef",
+    ".notdef", ".notdef", ".notdef", ".notdef", ".notdef", ".notdef",
+    ".notdef", ".notdef",
+    "space", "exclam", "quotedbl", "numbersign", "dollar", "percent",
+    "ampersand", "quotesingle", "parenleft", "parenright", "asterisk",
+    "plus", "comma", "hyphen", "period", "slash", "zero", "one", "two",
+    "three", "four", "five", "six", "seven", "eight", "nine", "colon",
+    "semicolon", "less", "equal", "greater", "question", "at", "A", "B",
+    "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P",
+    "Q", "R", "S", "T", "U", "V", "W", "X", "Y", "Z", "bracketleft",
+    "backslash", "bracketright", "asciicircum", "underscore", "grave",
+    "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n",
+    "o", "p", "q", "r", "s", "t", "u", "v", "w", "x", "y", "z",
+    "braceleft", "bar", "braceright", "asciitilde", ".notdef",
+    // 0x80..=0xFF: the "high" half of MacRoman.
+    "Adieresis", "Aring", "Ccedilla", "Eacute", "Ntilde", "Odieresis",
+    "Udieresis", "aacute", "agrave", "acircumflex", "adieresis",
+    "atilde", "aring", "ccedilla", "eacute", "egrave", "ecircumflex",
+    "edieresis", "iacute", "igrave", "icircumflex", "idieresis",
+    "ntilde", "oacute", "ograve", "ocircumflex", "odieresis", "otilde",
+    "uacute", "ugrave", "ucircumflex", "udieresis", "dagger", "degree",
+    "cent", "sterling", "section", "bullet", "paragraph", "germandbls",
+    "registered", "copyright", "trademark", "acute", "dieresis",
+    "notequal", "AE", "Oslash", "infinity", "plusminus", "lessequal",
+    "greaterequal", "yen", "mu", "partialdiff", "summation", "product",
+    "pi", "integral", "ordfeminine", "ordmasculine", "Omega", "ae",
+    "oslash", "questiondown", "exclamdown", "logicalnot", "radical",
+    "florin", "approxequal", "Delta", "guillemotleft", "guillemotright",
+    "ellipsis", "nonbreakingspace", "Agrave", "Atilde", "Otilde", "OE",
+    "oe", "endash", "emdash", "quotedblleft", "quotedblright",
+    "quoteleft", "quoteright", "divide", "lozenge", "ydieresis",
+    "Ydieresis", "fraction", "currency", "guilsinglleft",
+    "guilsinglright", "fi", "fl", "daggerdbl", "periodcentered",
+    "quotesinglbase", "quotedblbase", "perthousand", "Acircumflex",
+    "Ecircumflex", "Aacute", "Edieresis", "Egrave", "Iacute",
+    "Icircumflex", "Idieresis", "Igrave", "Oacute", "Ocircumflex",
+    "apple", "Ograve", "Uacute", "Ucircumflex", "Ugrave", "dotlessi",
+    "circumflex", "tilde", "macron", "breve", "dotaccent", "ring",
+    "cedilla", "hungarumlaut", "ogonek", "caron",
+];
+
+struct MacRomanDecoder<'a> {
+    pos: usize,
+    rem: &'a [u8],
+    mode: CurrencyMode,
+}
+
+impl Iterator for MacRomanDecoder<'_> {
+    type Item = (usize, usize, char);
+    fn next(&mut self) -> Option<(usize, usize, char)> {
+        let (&byte, rest) = self.rem.split_first()?;
+        let pos = self.pos;
+        self.rem = rest;
+        self.pos += 1;
+        let ch = if byte == 219 && self.mode == CurrencyMode::PreMacOS85 {
+            '\u{00A4}'
+        } else {
+            DECODE_TABLE[byte as usize]
+        };
+        Some((pos, 1, ch))
+    }
+}
+
+/// Decodes MacRoman bytes into Unicode, assuming byte 219 ($DB) means
+/// U+20AC (€). Use [`decode_with_mode`] to pick U+00A4 (¤) instead, for
+/// fonts and documents that predate Mac OS 8.5.
+///
+/// Every byte value decodes to some character, so unlike [`encode`] this
+/// iterator never has an error case.
+pub fn decode(input: &[u8]) -> impl '_ + Iterator<Item = char> {
+    decode_with_mode(input, CurrencyMode::default()).map(|(_pos, _len, c)| c)
+}
+
+/// Like [`decode`], but also yields the byte offset and length (always 1)
+/// of the MacRoman byte each character came from, paralleling [`encode`].
+pub fn decode_indices(
+    input: &[u8],
+) -> impl '_ + Iterator<Item = (usize, usize, char)> {
+    decode_with_mode(input, CurrencyMode::default())
+}
+
+/// Like [`decode_indices`], but lets the caller choose what byte 219
+/// ($DB) means. See [`CurrencyMode`].
+pub fn decode_with_mode(
+    input: &[u8],
+    mode: CurrencyMode,
+) -> impl '_ + Iterator<Item = (usize, usize, char)> {
+    MacRomanDecoder { pos: 0, rem: input, mode }
+}
+
+/// A Mac OS script encoding that [`encode_as`] can target.
+///
+/// Classic Mac OS shipped a family of single-byte "Mac *Script* Roman"
+/// encodings, one per writing system, each keyed off a numeric Mac script
+/// code and each reusing bytes 0–127 for ASCII. This crate currently
+/// ships MacRoman (the default, and the only one [`encode`] uses) and
+/// MacCentralEuropean; more can be added the same way.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum MacEncoding {
+    /// The original Mac OS Roman encoding (Mac script code 0), used for
+    /// Western European languages.
+    #[default]
+    MacRoman,
+    /// Mac OS Central European encoding, used for Czech, Hungarian,
+    /// Polish, Romanian, and other Central/Eastern European languages
+    /// written with the Latin alphabet.
+    ///
+    /// Only precomposed letters are recognized in this encoding; a base
+    /// letter followed by a combining mark is not combined.
+    MacCentralEuropean,
+}
+
+impl MacEncoding {
+    fn sequences(self) -> &'static [(&'static str, u8)] {
+        match self {
+            MacEncoding::MacRoman => KNOWN_SEQUENCES,
+            MacEncoding::MacCentralEuropean => CENTRAL_EUROPEAN_SEQUENCES,
+        }
+    }
+}
+
+/// Builds the set of ASCII bytes (0–127) that are the first character of
+/// some multi-character entry in `sequences` — i.e. base letters that
+/// could be followed by a combining mark, like the `A` in `A`+U+0300.
+/// These can never take the plain-ASCII fast path in
+/// [`MacRomanEncoder::next`], since doing so would mean never noticing
+/// the combining mark that follows.
+fn compute_starters(sequences: &[(&str, u8)]) -> [bool; 128] {
+    let mut starters = [false; 128];
+    for (sequence, _) in sequences {
+        let mut chars = sequence.chars();
+        let first = chars.next();
+        if chars.next().is_some() {
+            // More than one char in this sequence: its first char is a
+            // base that something can combine with.
+            if let Some(first) = first {
+                if (first as u32) < 0x80 {
+                    starters[first as usize] = true;
+                }
+            }
+        }
+    }
+    starters
+}
+
+#[inline]
+fn is_plain_ascii(starters: &[bool; 128], byte: u8) -> bool {
+    byte < 0x80 && !starters[byte as usize]
+}
+
+/// Returns the length of the leading run of `bytes` that are plain ASCII
+/// (per [`is_plain_ascii`]). `bytes[0]` must already be known to qualify,
+/// so the result is always at least 1.
+#[cfg(feature = "memchr")]
+fn ascii_run_len(starters: &[bool; 128], bytes: &[u8]) -> usize {
+    let ascii_end =
+        bytes.iter().position(|&b| b >= 0x80).unwrap_or(bytes.len());
+    let ascii = &bytes[..ascii_end];
+    let mut letters = [0u8; 128];
+    let mut letter_count = 0;
+    for (byte, &is_starter) in starters.iter().enumerate() {
+        if is_starter {
+            letters[letter_count] = byte as u8;
+            letter_count += 1;
+        }
+    }
+    let mut best = ascii_end;
+    let mut chunk_start = 0;
+    while chunk_start < letter_count {
+        let chunk = &letters[chunk_start..(chunk_start + 3).min(letter_count)];
+        let found = match *chunk {
+            [a, b, c] => memchr::memchr3(a, b, c, ascii),
+            [a, b] => memchr::memchr2(a, b, ascii),
+            [a] => memchr::memchr(a, ascii),
+            [] => None,
+            _ => unreachable!("chunk is at most 3 bytes"),
+        };
+        if let Some(offset) = found {
+            best = best.min(offset);
+        }
+        chunk_start += 3;
+    }
+    best
+}
+
+/// Returns the length of the leading run of `bytes` that are plain ASCII
+/// (per [`is_plain_ascii`]). `bytes[0]` must already be known to qualify,
+/// so the result is always at least 1.
+#[cfg(not(feature = "memchr"))]
+fn ascii_run_len(starters: &[bool; 128], bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .position(|&b| !is_plain_ascii(starters, b))
+        .unwrap_or(bytes.len())
+}
+
 struct MacRomanEncoder<'a> {
     pos: usize,
     rem: &'a str,
+    sequences: &'static [(&'static str, u8)],
+    lossy: bool,
+    // A best-fit substitution can expand to more than one output byte.
+    // Once that happens, these bytes are drained before `rem` is looked
+    // at again; only the first one reports the real (pos, len) of the
+    // input it came from, the rest report a zero-length span right after
+    // it, so that summing `len` over the whole iterator still accounts
+    // for every input byte exactly once.
+    pending: &'static [u8],
+    pending_count: usize,
+    pending_pos: usize,
+    pending_len: usize,
+    // Bytes of `rem` (still to come) already known to be a run of plain
+    // ASCII, i.e. not needing a binary search to resolve. Lets long runs
+    // of uninteresting ASCII (most English text) be scanned for the next
+    // "interesting" byte once per run, rather than binary-searched one
+    // byte at a time.
+    starters: [bool; 128],
+    fast_run: usize,
 }
 
 impl Iterator for MacRomanEncoder<'_> {
     type Item = (usize, usize, Result<u8, char>);
     fn next(&mut self) -> Option<(usize, usize, Result<u8, char>)> {
+        if let Some((&byte, rest)) = self.pending.split_first() {
+            let is_first = self.pending.len() == self.pending_count;
+            self.pending = rest;
+            return Some(if is_first {
+                (self.pending_pos, self.pending_len, Ok(byte))
+            } else {
+                (self.pending_pos + self.pending_len, 0, Ok(byte))
+            });
+        }
         if self.rem.is_empty() {
-            None
-        } else {
-            let pos = self.pos;
-            let best = match KNOWN_SEQUENCES
+            return None;
+        }
+        let pos = self.pos;
+        if self.fast_run == 0 {
+            let bytes = self.rem.as_bytes();
+            if is_plain_ascii(&self.starters, bytes[0]) {
+                self.fast_run = ascii_run_len(&self.starters, bytes);
+            }
+        }
+        if self.fast_run > 0 {
+            let byte = self.rem.as_bytes()[0];
+            self.rem = &self.rem[1..];
+            self.pos += 1;
+            self.fast_run -= 1;
+            return Some((pos, 1, Ok(byte)));
+        }
+        let best = match self
+            .sequences
+            .binary_search_by(|(prefix, _)| prefix.cmp(&self.rem))
+        {
+            Ok(x) => x,
+            Err(x) => x.saturating_sub(1),
+        };
+        if best < self.sequences.len() {
+            let (sequence, code) = self.sequences[best];
+            if let Some(rest) = self.rem.strip_prefix(sequence) {
+                self.rem = rest;
+                self.pos += sequence.len();
+                return Some((pos, sequence.len(), Ok(code)));
+            }
+        }
+        if self.lossy {
+            let best = match TRANSLITERATIONS
                 .binary_search_by(|(prefix, _)| prefix.cmp(&self.rem))
             {
                 Ok(x) => x,
                 Err(x) => x.saturating_sub(1),
             };
-            if best < KNOWN_SEQUENCES.len() {
-                let (sequence, code) = KNOWN_SEQUENCES[best];
+            if best < TRANSLITERATIONS.len() {
+                let (sequence, bytes) = TRANSLITERATIONS[best];
                 if let Some(rest) = self.rem.strip_prefix(sequence) {
                     self.rem = rest;
                     self.pos += sequence.len();
-                    return Some((pos, sequence.len(), Ok(code)));
+                    self.pending = bytes;
+                    self.pending_count = bytes.len();
+                    self.pending_pos = pos;
+                    self.pending_len = sequence.len();
+                    return self.next();
                 }
             }
-            let codepoint = self.rem.chars().next().unwrap();
-            let len = self
-                .rem
-                .char_indices()
-                .nth(1)
-                .map(|(i, _)| i)
-                .unwrap_or(self.rem.len());
-            self.rem = &self.rem[len..];
-            self.pos += len;
-            Some((pos, len, Err(codepoint)))
         }
+        let codepoint = self.rem.chars().next().unwrap();
+        let len = self
+            .rem
+            .char_indices()
+            .nth(1)
+            .map(|(i, _)| i)
+            .unwrap_or(self.rem.len());
+        self.rem = &self.rem[len..];
+        self.pos += len;
+        Some((pos, len, Err(codepoint)))
     }
 }
 
+/// Encodes `input` as MacRoman. Equivalent to
+/// `encode_as(input, MacEncoding::MacRoman)`.
 pub fn encode(
     input: &str,
 ) -> impl '_ + Iterator<Item = (usize, usize, Result<u8, char>)> {
-    MacRomanEncoder { pos: 0, rem: input }
+    encode_as(input, MacEncoding::MacRoman)
+}
+
+/// Encodes `input` as the given [`MacEncoding`].
+pub fn encode_as(
+    input: &str,
+    encoding: MacEncoding,
+) -> impl '_ + Iterator<Item = (usize, usize, Result<u8, char>)> {
+    let sequences = encoding.sequences();
+    MacRomanEncoder {
+        pos: 0,
+        rem: input,
+        sequences,
+        lossy: false,
+        pending: &[],
+        pending_count: 0,
+        pending_pos: 0,
+        pending_len: 0,
+        starters: compute_starters(sequences),
+        fast_run: 0,
+    }
+}
+
+/// Encodes `input` as MacRoman, best-fit: characters with no direct
+/// MacRoman equivalent are first looked up in a small table of ASCII and
+/// MacRoman approximations (fullwidth ASCII forms, Unicode space and
+/// dash variants, and the like) before giving up. Equivalent to
+/// `encode_as_lossy(input, MacEncoding::MacRoman)`.
+///
+/// This is meant for displaying otherwise-unencodable text rather than
+/// for lossless round-tripping: unlike [`encode`], a substitution here
+/// can yield more than one MacRoman byte for a single input character
+/// (and in the `(pos, len, byte)` triples, every byte but the first one
+/// of a substitution reports a zero-length span).
+pub fn encode_lossy(
+    input: &str,
+) -> impl '_ + Iterator<Item = (usize, usize, Result<u8, char>)> {
+    encode_as_lossy(input, MacEncoding::MacRoman)
+}
+
+/// Like [`encode_lossy`], but for the given [`MacEncoding`].
+pub fn encode_as_lossy(
+    input: &str,
+    encoding: MacEncoding,
+) -> impl '_ + Iterator<Item = (usize, usize, Result<u8, char>)> {
+    let sequences = encoding.sequences();
+    MacRomanEncoder {
+        pos: 0,
+        rem: input,
+        sequences,
+        lossy: true,
+        pending: &[],
+        pending_count: 0,
+        pending_pos: 0,
+        pending_len: 0,
+        starters: compute_starters(sequences),
+        fast_run: 0,
+    }
+}
+
+/// Returns the classic Macintosh PostScript glyph name for a MacRoman
+/// byte value, e.g. `"adieresis"` for $8A. Useful for looking a glyph up
+/// by name in a font's `post` table rather than by byte index. See
+/// [`MACROMAN_GLYPH_NAMES`] for how control characters and the currency
+/// sign are named.
+pub fn macroman_glyph_name(byte: u8) -> &'static str {
+    MACROMAN_GLYPH_NAMES[byte as usize]
+}
+
+/// Like [`encode`], but yields the MacRoman glyph name for each encoded
+/// byte (see [`macroman_glyph_name`]) instead of the byte itself.
+pub fn encode_glyph_names(
+    input: &str,
+) -> impl '_ + Iterator<Item = (usize, usize, Result<&'static str, char>)> {
+    encode(input)
+        .map(|(pos, len, result)| (pos, len, result.map(macroman_glyph_name)))
+}
+
+/// Like [`encode_lossy`], but yields glyph names instead of bytes; see
+/// [`encode_glyph_names`].
+pub fn encode_glyph_names_lossy(
+    input: &str,
+) -> impl '_ + Iterator<Item = (usize, usize, Result<&'static str, char>)> {
+    encode_lossy(input)
+        .map(|(pos, len, result)| (pos, len, result.map(macroman_glyph_name)))
 }
 
 #[cfg(test)]
@@ -437,4 +1278,124 @@ mod test {
             DST
         )
     }
+    #[test]
+    fn quebecois_glass_round_trip() {
+        const SRC: &[u8] = b"J'peux manger d'la vitre, \x8Da m'fa pas mal.";
+        const DST: &str = "J'peux manger d'la vitre, \u{00E7}a m'fa pas mal.";
+        assert_eq!(decode(SRC).collect::<String>(), DST);
+    }
+    #[test]
+    fn currency_mode() {
+        const SRC: &[u8] = b"\xDB";
+        assert_eq!(decode(SRC).collect::<Vec<char>>(), vec!['\u{20AC}']);
+        assert_eq!(
+            decode_with_mode(SRC, CurrencyMode::PreMacOS85)
+                .map(|(_pos, _len, c)| c)
+                .collect::<Vec<char>>(),
+            vec!['\u{00A4}']
+        );
+    }
+    #[test]
+    fn omega_decodes_as_letter() {
+        const SRC: &[u8] = b"\xBD";
+        assert_eq!(decode(SRC).collect::<Vec<char>>(), vec!['\u{03A9}']);
+    }
+    #[test]
+    fn central_european_letters() {
+        const SRC: &str = "Čau, přítel!";
+        const DST: &[u8] = b"\x89au, p\xA9\x92tel!";
+        assert_eq!(
+            encode_as(SRC, MacEncoding::MacCentralEuropean)
+                .map(|(_pos, _len, c)| c)
+                .collect::<Result<Vec<u8>, char>>()
+                .unwrap(),
+            DST
+        )
+    }
+    #[test]
+    fn default_encoding_is_mac_roman() {
+        const SRC: &str = "café";
+        assert_eq!(
+            encode(SRC)
+                .map(|(_pos, _len, c)| c)
+                .collect::<Result<Vec<u8>, char>>()
+                .unwrap(),
+            encode_as(SRC, MacEncoding::default())
+                .map(|(_pos, _len, c)| c)
+                .collect::<Result<Vec<u8>, char>>()
+                .unwrap()
+        )
+    }
+    #[test]
+    fn best_fit_minus_and_fullwidth() {
+        const SRC: &str = "5\u{2212}3=\u{FF12}";
+        const DST: &[u8] = b"5-3=2";
+        assert_eq!(
+            encode_lossy(SRC)
+                .map(|(_pos, _len, c)| c)
+                .collect::<Result<Vec<u8>, char>>()
+                .unwrap(),
+            DST
+        )
+    }
+    #[test]
+    fn best_fit_still_fails_on_greek() {
+        const SRC: &str = "\u{03B1}";
+        assert_eq!(
+            encode_lossy(SRC)
+                .map(|(_pos, _len, c)| c)
+                .collect::<Vec<Result<u8, char>>>(),
+            vec![Err('\u{03B1}')]
+        )
+    }
+    #[test]
+    fn best_fit_pos_len_accounting() {
+        // Spans of every item must sum to the whole input length, even
+        // though one input character became three output bytes.
+        const SRC: &str = "x\u{00BD}y";
+        let items: Vec<_> = encode_lossy(SRC).collect();
+        let total: usize = items.iter().map(|(_pos, len, _)| *len).sum();
+        assert_eq!(total, SRC.len());
+        assert_eq!(
+            items.into_iter().map(|(_pos, _len, c)| c).collect::<Vec<_>>(),
+            vec![Ok(b'x'), Ok(b'1'), Ok(b'/'), Ok(b'2'), Ok(b'y')]
+        );
+    }
+    #[test]
+    fn ascii_fast_path_still_combines() {
+        // A long boring ASCII run followed by a base letter that
+        // combines with the next char must not be fast-pathed past.
+        const SRC: &str = "some plain text then A\u{0300} at the end";
+        assert_eq!(
+            encode(SRC)
+                .map(|(_pos, _len, c)| c)
+                .collect::<Result<Vec<u8>, char>>()
+                .unwrap(),
+            b"some plain text then \xCB at the end"
+        )
+    }
+    #[test]
+    fn glyph_names() {
+        const SRC: &str = "fi café ƒ";
+        assert_eq!(
+            encode_glyph_names(SRC)
+                .map(|(_pos, _len, name)| name)
+                .collect::<Result<Vec<&str>, char>>()
+                .unwrap(),
+            vec![
+                "f", "i", "space", "c", "a", "f", "eacute", "space",
+                "florin",
+            ]
+        )
+    }
+    #[test]
+    fn glyph_name_examples() {
+        assert_eq!(macroman_glyph_name(0), ".notdef");
+        assert_eq!(macroman_glyph_name(32), "space");
+        assert_eq!(macroman_glyph_name(65), "A");
+        assert_eq!(macroman_glyph_name(138), "adieresis");
+        assert_eq!(macroman_glyph_name(196), "florin");
+        assert_eq!(macroman_glyph_name(222), "fi");
+        assert_eq!(macroman_glyph_name(240), "apple");
+    }
 }